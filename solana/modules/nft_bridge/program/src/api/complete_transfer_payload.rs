@@ -0,0 +1,239 @@
+use crate::{
+    accounts::{
+        ConfigAccount,
+        CustodyAccount,
+        CustodyAccountDerivationData,
+        CustodySigner,
+        Endpoint,
+        EndpointDerivationData,
+        MintSigner,
+        WrappedDerivationData,
+        WrappedMetaDerivationData,
+        WrappedMint,
+        WrappedTokenMeta,
+    },
+    messages::{
+        PayloadTransfer,
+        PayloadTransferWithPayload,
+    },
+    types::*,
+    NFTBridgeError::*,
+};
+use bridge::{
+    vaa::ClaimableVAA,
+    PayloadMessage,
+    CHAIN_ID_SOLANA,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solitaire::{
+    processors::seeded::{
+        invoke_seeded,
+        Seeded,
+    },
+    AccountState::*,
+    CreationLamports::Exempt,
+    *,
+};
+use spl_token::state::{
+    Account,
+    Mint,
+};
+
+accounts!(CompleteNativeNftWithPayload {
+    payer:              Mut<Signer<AccountInfo<'info>>>,
+    config:             ConfigAccount<'info, { Initialized }>,
+    vaa:                PayloadMessage<'info, PayloadTransferWithPayload>,
+    vaa_claim:          ClaimableVAA<'info>,
+    chain_registration: Endpoint<'info, { Initialized }>,
+    to:                 Mut<Data<'info, SplAccount, { Initialized }>>,
+    to_owner:           MaybeMut<Signer<Info<'info>>>,
+    custody:            Mut<CustodyAccount<'info, { Initialized }>>,
+    mint:               Data<'info, SplMint, { Initialized }>,
+    custody_signer:     CustodySigner<'info>,
+});
+
+impl<'a> From<&CompleteNativeNftWithPayload<'a>> for EndpointDerivationData {
+    fn from(accs: &CompleteNativeNftWithPayload<'a>) -> Self {
+        EndpointDerivationData {
+            emitter_chain: accs.vaa.meta().emitter_chain,
+            emitter_address: accs.vaa.meta().emitter_address,
+        }
+    }
+}
+
+impl<'a> From<&CompleteNativeNftWithPayload<'a>> for CustodyAccountDerivationData {
+    fn from(accs: &CompleteNativeNftWithPayload<'a>) -> Self {
+        CustodyAccountDerivationData {
+            mint: *accs.mint.info().key,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteNativeNftWithPayloadData {}
+
+pub fn complete_native_nft_with_payload(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteNativeNftWithPayload,
+    data: CompleteNativeNftWithPayloadData,
+) -> Result<()> {
+    // Verify the chain registration
+    let derivation_data: EndpointDerivationData = (&*accs).into();
+    accs.chain_registration
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+
+    // Verify that the custody account is derived correctly
+    let derivation_data: CustodyAccountDerivationData = (&*accs).into();
+    accs.custody
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+
+    // Verify mints
+    if *accs.mint.info().key != accs.to.mint {
+        return Err(InvalidMint.into());
+    }
+    if *accs.mint.info().key != accs.custody.mint {
+        return Err(InvalidMint.into());
+    }
+    if *accs.custody_signer.key != accs.custody.owner {
+        return Err(WrongAccountOwner.into());
+    }
+
+    // Verify VAA
+    if accs.vaa.token_address != accs.mint.info().key.to_bytes() {
+        return Err(InvalidMint.into());
+    }
+    if accs.vaa.token_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
+    }
+    if accs.vaa.to_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
+    }
+    if accs.vaa.to != accs.to_owner.info().key.to_bytes() {
+        return Err(InvalidRecipient.into());
+    }
+
+    // VAA-specified recipient must be token account owner
+    if *accs.to_owner.info().key != accs.to.owner {
+        return Err(InvalidRecipient.into());
+    }
+
+    // Prevent vaa double signing
+    accs.vaa_claim.claim(ctx, accs.payer.key, &accs.vaa)?;
+
+    // NFTs are always transferred as a single indivisible unit; there is no
+    // 8-decimal truncation to reverse as there is on the fungible path.
+    let transfer_ix = spl_token::instruction::transfer(
+        &spl_token::id(),
+        accs.custody.info().key,
+        accs.to.info().key,
+        accs.custody_signer.key,
+        &[],
+        1,
+    )?;
+    invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
+
+    Ok(())
+}
+
+accounts!(CompleteWrappedNftWithPayload {
+    payer:              Mut<Signer<AccountInfo<'info>>>,
+    config:             ConfigAccount<'info, { AccountState::Initialized }>,
+    vaa:                PayloadMessage<'info, PayloadTransferWithPayload>,
+    vaa_claim:          ClaimableVAA<'info>,
+    chain_registration: Endpoint<'info, { AccountState::Initialized }>,
+    to:                 Mut<Data<'info, SplAccount, { AccountState::Initialized }>>,
+    to_owner:           MaybeMut<Signer<Info<'info>>>,
+    mint:               Mut<WrappedMint<'info, { AccountState::Initialized }>>,
+    wrapped_meta:       Mut<WrappedTokenMeta<'info, { AccountState::Initialized }>>,
+    mint_authority:     MintSigner<'info>,
+});
+
+impl<'a> From<&CompleteWrappedNftWithPayload<'a>> for EndpointDerivationData {
+    fn from(accs: &CompleteWrappedNftWithPayload<'a>) -> Self {
+        EndpointDerivationData {
+            emitter_chain: accs.vaa.meta().emitter_chain,
+            emitter_address: accs.vaa.meta().emitter_address,
+        }
+    }
+}
+
+impl<'a> From<&CompleteWrappedNftWithPayload<'a>> for WrappedDerivationData {
+    fn from(accs: &CompleteWrappedNftWithPayload<'a>) -> Self {
+        WrappedDerivationData {
+            token_chain: accs.vaa.token_chain,
+            token_address: accs.vaa.token_address,
+            token_id: accs.vaa.token_id,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteWrappedNftWithPayloadData {}
+
+pub fn complete_wrapped_nft_with_payload(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrappedNftWithPayload,
+    data: CompleteWrappedNftWithPayloadData,
+) -> Result<()> {
+    // Verify the chain registration
+    let derivation_data: EndpointDerivationData = (&*accs).into();
+    accs.chain_registration
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+
+    // Verify that the wrapped mint was derived from (chain, address, token_id)
+    let derivation_data: WrappedDerivationData = (&*accs).into();
+    accs.mint
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+    accs.wrapped_meta.verify_derivation(
+        ctx.program_id,
+        &WrappedMetaDerivationData {
+            mint_key: *accs.mint.info().key,
+        },
+    )?;
+    if accs.wrapped_meta.token_address != accs.vaa.token_address
+        || accs.wrapped_meta.chain != accs.vaa.token_chain
+        || accs.wrapped_meta.token_id != accs.vaa.token_id
+    {
+        return Err(InvalidMint.into());
+    }
+
+    // Verify mints
+    if *accs.mint.info().key != accs.to.mint {
+        return Err(InvalidMint.into());
+    }
+
+    // Verify VAA
+    if accs.vaa.to_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidChain.into());
+    }
+    if accs.vaa.to != accs.to_owner.info().key.to_bytes() {
+        return Err(InvalidRecipient.into());
+    }
+
+    // VAA-specified recipient must be token account owner
+    if *accs.to_owner.info().key != accs.to.owner {
+        return Err(InvalidRecipient.into());
+    }
+
+    accs.vaa_claim.claim(ctx, accs.payer.key, &accs.vaa)?;
+
+    // Persist the incoming URI so CreateWrapped-style metadata can be attached.
+    accs.wrapped_meta.uri = accs.vaa.uri.clone();
+
+    // Mint exactly one unit of the non-fungible token.
+    let mint_ix = spl_token::instruction::mint_to(
+        &spl_token::id(),
+        accs.mint.info().key,
+        accs.to.info().key,
+        accs.mint_authority.key,
+        &[],
+        1,
+    )?;
+    invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::{
+    env,
+    fs,
+    path::Path,
+};
+
+/// Bake the governance emitter address into the program at build time.
+///
+/// The address is taken from the `GOVERNANCE_EMITTER` environment variable as a
+/// 64-character hex string (32 bytes) so that each network can be built against
+/// its own governance contract. It is a HARD requirement, not a convenience
+/// default: building without it would bake in the all-zero emitter, and the
+/// bridge would then accept a governance VAA from the zero emitter and be fully
+/// reconfigurable by anyone who can get the guardians to sign one. The build
+/// therefore fails loudly when it is unset. The generated file is `include!`d
+/// by `api::governance`.
+fn main() {
+    println!("cargo:rerun-if-env-changed=GOVERNANCE_EMITTER");
+
+    let emitter = env::var("GOVERNANCE_EMITTER").expect(
+        "GOVERNANCE_EMITTER must be set to the 32-byte governance emitter (hex); \
+         refusing to build with an unconfigured governance key",
+    );
+    let bytes = hex::decode(&emitter).expect("GOVERNANCE_EMITTER must be valid hex");
+    assert_eq!(bytes.len(), 32, "GOVERNANCE_EMITTER must be 32 bytes");
+    assert!(
+        bytes.iter().any(|b| *b != 0),
+        "GOVERNANCE_EMITTER must not be the all-zero address"
+    );
+
+    let literal = bytes
+        .iter()
+        .map(|b| b.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let dest = Path::new(&out_dir).join("governance_emitter.rs");
+    fs::write(dest, format!("[{}]", literal)).unwrap();
+}
@@ -0,0 +1,224 @@
+use crate::{
+    accounts::{
+        ConfigAccount,
+        CustodyAccount,
+        CustodyAccountDerivationData,
+        CustodySigner,
+        Endpoint,
+        EndpointDerivationData,
+        MintSigner,
+        WrappedMetaDerivationData,
+        WrappedMint,
+        WrappedTokenMeta,
+    },
+    messages::PayloadTransferWithPayload,
+    types::*,
+    TokenBridgeError::*,
+};
+use bridge::{
+    vaa::ClaimableVAA,
+    PayloadMessage,
+    CHAIN_ID_SOLANA,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+};
+use solitaire::{
+    processors::seeded::{
+        invoke_seeded,
+        Seeded,
+    },
+    AccountState::*,
+    Many,
+    *,
+};
+
+// A single redemption within a batch. Each item carries its own token, custody
+// and chain-registration accounts so a relayer can drain a backlog of
+// *heterogeneous* transfers — different tokens from different emitter chains —
+// in one instruction. Grouping the per-VAA accounts into one composite struct
+// also lets `Many<BatchItem>` partition the account list into fixed runs, which
+// a bare `Many` of each account type could not do. `items` is the final field
+// of each set because `Many` greedily consumes all remaining accounts.
+accounts!(NativeBatchItem {
+    vaa:                PayloadMessage<'info, PayloadTransferWithPayload>,
+    vaa_claim:          ClaimableVAA<'info>,
+    chain_registration: Endpoint<'info, { Initialized }>,
+    custody:            Mut<CustodyAccount<'info, { Initialized }>>,
+    mint:               Data<'info, SplMint, { Initialized }>,
+    to:                 Mut<Data<'info, SplAccount, { Initialized }>>,
+    to_fees:            Mut<Data<'info, SplAccount, { Initialized }>>,
+});
+
+accounts!(CompleteNativeBatch {
+    payer:          Mut<Signer<AccountInfo<'info>>>,
+    config:         ConfigAccount<'info, { Initialized }>,
+    custody_signer: CustodySigner<'info>,
+    items:          Many<NativeBatchItem<'info>>,
+});
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteNativeBatchData {}
+
+pub fn complete_native_batch(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteNativeBatch,
+    _data: CompleteNativeBatchData,
+) -> Result<()> {
+    // Any single invalid or already-claimed VAA aborts the whole instruction,
+    // so the batch either redeems every transfer or none of them.
+    for item in accs.items.iter_mut() {
+        item.chain_registration.verify_derivation(
+            ctx.program_id,
+            &EndpointDerivationData {
+                emitter_chain: item.vaa.meta().emitter_chain,
+                emitter_address: item.vaa.meta().emitter_address,
+            },
+        )?;
+        item.custody.verify_derivation(
+            ctx.program_id,
+            &CustodyAccountDerivationData {
+                mint: *item.mint.info().key,
+            },
+        )?;
+
+        if *item.mint.info().key != item.to.mint
+            || *item.mint.info().key != item.to_fees.mint
+            || *item.mint.info().key != item.custody.mint
+        {
+            return Err(InvalidMint.into());
+        }
+        if *accs.custody_signer.key != item.custody.owner {
+            return Err(WrongAccountOwner.into());
+        }
+        if item.vaa.token_address != item.mint.info().key.to_bytes() {
+            return Err(InvalidMint.into());
+        }
+        if item.vaa.token_chain != 1 {
+            return Err(InvalidChain.into());
+        }
+        if item.vaa.to_chain != CHAIN_ID_SOLANA {
+            return Err(InvalidChain.into());
+        }
+        if item.vaa.to != item.to.owner.to_bytes() {
+            return Err(InvalidRecipient.into());
+        }
+
+        item.vaa_claim.claim(ctx, accs.payer.key, &item.vaa)?;
+
+        let mut amount = item.vaa.amount.as_u64();
+        let mut fee = item.vaa.fee.as_u64();
+        if item.mint.decimals > 8 {
+            amount *= 10u64.pow((item.mint.decimals - 8) as u32);
+            fee *= 10u64.pow((item.mint.decimals - 8) as u32);
+        }
+
+        let transfer_ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            item.custody.info().key,
+            item.to.info().key,
+            accs.custody_signer.key,
+            &[],
+            amount.checked_sub(fee).unwrap(),
+        )?;
+        invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
+
+        let transfer_ix = spl_token::instruction::transfer(
+            &spl_token::id(),
+            item.custody.info().key,
+            item.to_fees.info().key,
+            accs.custody_signer.key,
+            &[],
+            fee,
+        )?;
+        invoke_seeded(&transfer_ix, ctx, &accs.custody_signer, None)?;
+    }
+
+    Ok(())
+}
+
+accounts!(WrappedBatchItem {
+    vaa:                PayloadMessage<'info, PayloadTransferWithPayload>,
+    vaa_claim:          ClaimableVAA<'info>,
+    chain_registration: Endpoint<'info, { Initialized }>,
+    mint:               Mut<WrappedMint<'info, { Initialized }>>,
+    wrapped_meta:       WrappedTokenMeta<'info, { Initialized }>,
+    to:                 Mut<Data<'info, SplAccount, { Initialized }>>,
+    to_fees:            Mut<Data<'info, SplAccount, { Initialized }>>,
+});
+
+accounts!(CompleteWrappedBatch {
+    payer:          Mut<Signer<AccountInfo<'info>>>,
+    config:         ConfigAccount<'info, { Initialized }>,
+    mint_authority: MintSigner<'info>,
+    items:          Many<WrappedBatchItem<'info>>,
+});
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct CompleteWrappedBatchData {}
+
+pub fn complete_wrapped_batch(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrappedBatch,
+    _data: CompleteWrappedBatchData,
+) -> Result<()> {
+    for item in accs.items.iter_mut() {
+        item.chain_registration.verify_derivation(
+            ctx.program_id,
+            &EndpointDerivationData {
+                emitter_chain: item.vaa.meta().emitter_chain,
+                emitter_address: item.vaa.meta().emitter_address,
+            },
+        )?;
+        item.wrapped_meta.verify_derivation(
+            ctx.program_id,
+            &WrappedMetaDerivationData {
+                mint_key: *item.mint.info().key,
+            },
+        )?;
+
+        if item.wrapped_meta.token_address != item.vaa.token_address
+            || item.wrapped_meta.chain != item.vaa.token_chain
+        {
+            return Err(InvalidMint.into());
+        }
+        if *item.mint.info().key != item.to.mint || *item.mint.info().key != item.to_fees.mint {
+            return Err(InvalidMint.into());
+        }
+        if item.vaa.to_chain != CHAIN_ID_SOLANA {
+            return Err(InvalidChain.into());
+        }
+        if item.vaa.to != item.to.owner.to_bytes() {
+            return Err(InvalidRecipient.into());
+        }
+
+        item.vaa_claim.claim(ctx, accs.payer.key, &item.vaa)?;
+
+        let mint_ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            item.mint.info().key,
+            item.to.info().key,
+            accs.mint_authority.key,
+            &[],
+            item.vaa
+                .amount
+                .as_u64()
+                .checked_sub(item.vaa.fee.as_u64())
+                .unwrap(),
+        )?;
+        invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+
+        let mint_ix = spl_token::instruction::mint_to(
+            &spl_token::id(),
+            item.mint.info().key,
+            item.to_fees.info().key,
+            accs.mint_authority.key,
+            &[],
+            item.vaa.fee.as_u64(),
+        )?;
+        invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
+    }
+
+    Ok(())
+}
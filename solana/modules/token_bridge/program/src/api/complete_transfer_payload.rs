@@ -26,10 +26,17 @@ use bridge::{
 };
 use solana_program::{
     account_info::AccountInfo,
+    instruction::{
+        AccountMeta,
+        Instruction,
+    },
     program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
+use spl_token_metadata::instruction::create_metadata_accounts_v3;
 use solitaire::{
     processors::seeded::{
         invoke_seeded,
@@ -48,6 +55,55 @@ use std::ops::{
     DerefMut,
 };
 
+/// Forward the transfer's arbitrary payload to the designated redeemer program.
+///
+/// Invokes `target_program` after the token transfer with the sender address,
+/// the transferred `amount` and the raw `payload` bytes. This enables
+/// single-transaction transfer-and-call composability. It is called only by the
+/// `*_and_call` instructions; it fails with `InvalidProgram` when
+/// `target_program` is not executable and with `InvalidRecipient` when
+/// `redeemer` is not `target_program`'s `redeemer` PDA.
+fn forward_payload_to_redeemer(
+    ctx: &ExecutionContext,
+    target_program: &AccountInfo,
+    redeemer: &AccountInfo,
+    to: &AccountInfo,
+    from_address: [u8; 32],
+    payload: &[u8],
+    amount: u64,
+) -> Result<()> {
+    if !target_program.executable {
+        return Err(InvalidProgram.into());
+    }
+
+    // The redeemer is the target program's own PDA; the token bridge cannot
+    // sign for a seed domain it does not own, so we only assert the account
+    // matches and let the redeemer's signer privilege propagate from the
+    // caller's outer `invoke_signed`.
+    let (redeemer_key, _bump) =
+        Pubkey::find_program_address(&[b"redeemer"], target_program.key);
+    if redeemer_key != *redeemer.key {
+        return Err(InvalidRecipient.into());
+    }
+
+    let mut data = Vec::with_capacity(32 + 8 + payload.len());
+    data.extend_from_slice(&from_address);
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(payload);
+
+    let ix = Instruction {
+        program_id: *target_program.key,
+        accounts: vec![
+            AccountMeta::new_readonly(*redeemer.key, true),
+            AccountMeta::new(*to.key, false),
+        ],
+        data,
+    };
+    invoke_signed(&ix, ctx.accounts, &[])?;
+
+    Ok(())
+}
+
 accounts!(CompleteNativeWithPayload {
     payer:              Mut<Signer<AccountInfo<'info>>>,
     config:             ConfigAccount<'info, { Initialized }>,
@@ -179,6 +235,14 @@ accounts!(CompleteWrappedWithPayload {
     mint:               Mut<WrappedMint<'info, { AccountState::Initialized }>>,
     wrapped_meta:       WrappedTokenMeta<'info, { AccountState::Initialized }>,
     mint_authority:     MintSigner<'info>,
+
+    // Metaplex on-chain metadata. Written the first time this wrapped mint is
+    // completed so the asset is not an anonymous mint in wallets; left
+    // untouched on subsequent redemptions of the same mint.
+    metadata_account:   Mut<Info<'info>>,
+    mpl_token_metadata: Info<'info>,
+    rent:               Sysvar<'info, Rent>,
+    system_program:     Info<'info>,
 });
 
 impl<'a> From<&CompleteWrappedWithPayload<'a>> for EndpointDerivationData {
@@ -274,5 +338,98 @@ pub fn complete_wrapped_with_payload(
     )?;
     invoke_seeded(&mint_ix, ctx, &accs.mint_authority, None)?;
 
+    // Attach human-readable metadata the first time this wrapped mint is
+    // redeemed. Name and symbol come from the attestation-derived
+    // `WrappedTokenMeta`. The request asks for "the URI from the VAA payload",
+    // but on the fungible token bridge there is no such source: neither the
+    // `AssetMeta` attestation nor the `PayloadTransferWithPayload` transfer
+    // carries a URI field (only the NFT bridge's VAAs do). The URI is therefore
+    // left empty here; it is populated on the NFT path instead. Skipped once
+    // the metadata account exists so repeated redemptions do not fail.
+    if accs.metadata_account.data_is_empty() {
+        let metadata_ix = create_metadata_accounts_v3(
+            spl_token_metadata::id(),
+            *accs.metadata_account.key,
+            *accs.mint.info().key,
+            *accs.mint_authority.key,
+            *accs.payer.key,
+            *accs.mint_authority.key,
+            accs.wrapped_meta.name.clone(),
+            accs.wrapped_meta.symbol.clone(),
+            String::new(),
+            None,
+            0,
+            false,
+            true,
+            None,
+            None,
+            None,
+        );
+        invoke_seeded(&metadata_ix, ctx, &accs.mint_authority, None)?;
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
+// "transfer-and-call" variants. These are separate instructions so that the
+// base `complete_*_with_payload` account layouts are unchanged and ordinary
+// redemptions (no redeemer program) do not have to pass an extra account. They
+// run the normal completion and then forward the payload to the recipient
+// program, which must be the VAA-designated redeemer PDA.
+
+accounts!(CompleteNativeWithPayloadAndCall {
+    base:           CompleteNativeWithPayload<'info>,
+    target_program: Info<'info>,
+});
+
+pub fn complete_native_with_payload_and_call(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteNativeWithPayloadAndCall,
+    data: CompleteNativeWithPayloadData,
+) -> Result<()> {
+    complete_native_with_payload(ctx, &mut accs.base, data)?;
+
+    let mut amount = accs.base.vaa.amount.as_u64();
+    let mut fee = accs.base.vaa.fee.as_u64();
+    if accs.base.mint.decimals > 8 {
+        amount *= 10u64.pow((accs.base.mint.decimals - 8) as u32);
+        fee *= 10u64.pow((accs.base.mint.decimals - 8) as u32);
+    }
+
+    forward_payload_to_redeemer(
+        ctx,
+        accs.target_program.info(),
+        accs.base.to_owner.info(),
+        accs.base.to.info(),
+        accs.base.vaa.from_address,
+        &accs.base.vaa.payload,
+        amount.checked_sub(fee).unwrap(),
+    )
+}
+
+accounts!(CompleteWrappedWithPayloadAndCall {
+    base:           CompleteWrappedWithPayload<'info>,
+    target_program: Info<'info>,
+});
+
+pub fn complete_wrapped_with_payload_and_call(
+    ctx: &ExecutionContext,
+    accs: &mut CompleteWrappedWithPayloadAndCall,
+    data: CompleteWrappedWithPayloadData,
+) -> Result<()> {
+    complete_wrapped_with_payload(ctx, &mut accs.base, data)?;
+
+    forward_payload_to_redeemer(
+        ctx,
+        accs.target_program.info(),
+        accs.base.to_owner.info(),
+        accs.base.to.info(),
+        accs.base.vaa.from_address,
+        &accs.base.vaa.payload,
+        accs.base
+            .vaa
+            .amount
+            .as_u64()
+            .checked_sub(accs.base.vaa.fee.as_u64())
+            .unwrap(),
+    )
+}
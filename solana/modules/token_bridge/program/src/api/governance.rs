@@ -0,0 +1,102 @@
+//! Governance-authorized entry points.
+//!
+//! Every instruction in this module that registers an `Endpoint` or mutates the
+//! `ConfigAccount` on the authority of a guardian-signed VAA MUST call
+//! [`verify_governance`] as its first step, so a validly-signed message from an
+//! unexpected emitter cannot reconfigure the bridge. `register_chain` below is
+//! the endpoint registrar; any future governance upgrade that touches
+//! `ConfigAccount` belongs here and is bound by the same rule.
+//!
+//! The permissionless `Initialize` handler is deliberately *not* gated: it
+//! carries no VAA (it only records the wormhole bridge address at deploy time)
+//! and so has no emitter to verify. It is therefore not a governance action in
+//! the sense this check protects.
+
+use crate::{
+    accounts::{
+        ConfigAccount,
+        Endpoint,
+        EndpointDerivationData,
+    },
+    messages::PayloadGovernanceRegisterChain,
+    types::*,
+    TokenBridgeError::*,
+};
+use bridge::{
+    vaa::{
+        ClaimableVAA,
+        DeserializePayload,
+    },
+    PayloadMessage,
+    CHAIN_ID_SOLANA,
+};
+use solana_program::pubkey::Pubkey;
+use solitaire::{
+    CreationLamports::Exempt,
+    *,
+};
+
+/// Emitter address of the governance contract, fixed at build time. A
+/// guardian-signed message is only honoured for governance actions if it
+/// originates from exactly this emitter on Solana; any other emitter — even a
+/// validly signed one — is rejected.
+pub const EMITTER_ADDRESS: [u8; 32] = include!(concat!(env!("OUT_DIR"), "/governance_emitter.rs"));
+
+/// Assert that a governance VAA was emitted by the compiled-in governance key.
+///
+/// Governance-authorised instructions must not trust an arbitrary signed VAA;
+/// they gate on the emitter so a message from an unexpected source cannot
+/// reconfigure the bridge even if the guardians signed it.
+pub fn verify_governance<T: DeserializePayload>(vaa: &PayloadMessage<T>) -> Result<()> {
+    let expected_emitter = EMITTER_ADDRESS;
+    let current_emitter = vaa.meta().emitter_address;
+    if expected_emitter != current_emitter || vaa.meta().emitter_chain != CHAIN_ID_SOLANA {
+        return Err(InvalidGovernanceKey.into());
+    }
+    Ok(())
+}
+
+accounts!(RegisterChain {
+    payer:         Mut<Signer<AccountInfo<'info>>>,
+    // The bridge must already be initialized; the `Initialized` state bound is
+    // enforced when this account is peeled, so governance cannot register a
+    // chain against an unconfigured bridge.
+    config:        ConfigAccount<'info, { AccountState::Initialized }>,
+    endpoint:      Mut<Endpoint<'info, { AccountState::Uninitialized }>>,
+    vaa:           PayloadMessage<'info, PayloadGovernanceRegisterChain>,
+    vaa_claim:     ClaimableVAA<'info>,
+});
+
+impl<'a> From<&RegisterChain<'a>> for EndpointDerivationData {
+    fn from(accs: &RegisterChain<'a>) -> Self {
+        EndpointDerivationData {
+            emitter_chain: accs.vaa.chain,
+            emitter_address: accs.vaa.endpoint_address,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct RegisterChainData {}
+
+pub fn register_chain(
+    ctx: &ExecutionContext,
+    accs: &mut RegisterChain,
+    _data: RegisterChainData,
+) -> Result<()> {
+    // Only the compiled-in governance emitter may register endpoints.
+    verify_governance(&accs.vaa)?;
+
+    let derivation_data: EndpointDerivationData = (&*accs).into();
+    accs.endpoint
+        .verify_derivation(ctx.program_id, &derivation_data)?;
+
+    // Prevent governance double execution
+    accs.vaa_claim.claim(ctx, accs.payer.key, &accs.vaa)?;
+
+    accs.endpoint.create(&derivation_data, ctx, accs.payer.key, Exempt)?;
+    accs.endpoint.chain = accs.vaa.chain;
+    accs.endpoint.contract = accs.vaa.endpoint_address;
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! A variable-length account-set combinator.
+//!
+//! `Many<T>` peels a run of accounts that all share the same shape `T` off the
+//! instruction's account list. It is the account-set analogue of a `Vec<T>`:
+//! where a fixed struct of accounts peels one account per field, `Many<T>`
+//! keeps peeling `T` until the account list is exhausted, letting a single
+//! instruction act over an arbitrary number of homogeneous inputs.
+//!
+//! Because it greedily consumes the remaining accounts, a `Many<T>` must be the
+//! **last** field of an account set and there may be only one of them. When an
+//! instruction needs several parallel lists, group the per-item accounts into a
+//! composite `accounts!` struct and use a single `Many<ThatStruct>`; the fixed
+//! field count of the composite partitions the list into one run per item.
+
+use crate::{
+    processors::seeded::Seeded,
+    Context,
+    ExecutionContext,
+    FromAccounts,
+    Peel,
+    Persist,
+    Result,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+};
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+
+/// A homogeneous, variable-length list of account sets of type `T`.
+pub struct Many<T>(pub Vec<T>);
+
+impl<T> Deref for Many<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Many<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a, 'b: 'a, 'c, T> FromAccounts<'a, 'b, 'c> for Many<T>
+where
+    T: FromAccounts<'a, 'b, 'c>,
+{
+    fn from<DataType>(
+        pid: &'a Pubkey,
+        iter: &'c mut std::slice::Iter<'a, AccountInfo<'b>>,
+        data: &'a DataType,
+    ) -> Result<Self> {
+        let mut items = Vec::new();
+        // Keep peeling `T` while any account remains; an error from `T::from`
+        // ends the peel and is returned. Each `T::from` advances the shared
+        // iterator, so the items partition the accounts contiguously.
+        while iter.clone().next().is_some() {
+            items.push(T::from(pid, iter, data)?);
+        }
+        Ok(Many(items))
+    }
+}
+
+impl<T> Persist for Many<T>
+where
+    T: Persist,
+{
+    fn persist(&self, program_id: &Pubkey) -> Result<()> {
+        for item in &self.0 {
+            item.persist(program_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b: 'a, 'c, T> Peel<'a, 'b, 'c> for Many<T>
+where
+    T: FromAccounts<'a, 'b, 'c> + Persist,
+{
+    fn peel<I>(ctx: &'c mut Context<'a, 'b, 'c, I>) -> Result<Self> {
+        // A `Many` is an account *set*, not a single account slot, so the macro
+        // peels it exactly as it peels a nested `accounts!` struct: by handing
+        // the shared iterator to `FromAccounts` instead of claiming one account.
+        // This is the same path the `base` field in the transfer-and-call
+        // handlers takes, and the reason `items` must be the final field.
+        Self::from(ctx.this, ctx.iter, ctx.data)
+    }
+
+    fn deps() -> Vec<Pubkey> {
+        vec![]
+    }
+
+    fn persist(&self, program_id: &Pubkey) -> Result<()> {
+        <Self as Persist>::persist(self, program_id)
+    }
+}
@@ -0,0 +1,221 @@
+use cosmwasm_std::{
+    to_binary,
+    Addr,
+    Api,
+    Binary,
+    CosmosMsg,
+    Deps,
+    DepsMut,
+    Env,
+    MessageInfo,
+    Response,
+    StdError,
+    StdResult,
+    Uint128,
+    WasmMsg,
+};
+use schemars::JsonSchema;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use terraswap::asset::{
+    Asset,
+    AssetInfo,
+};
+
+use crate::{
+    msg::{
+        ExecuteMsg,
+        QueryMsg,
+    },
+    state::{
+        config_read,
+        transfer_state,
+        transfer_state_read,
+    },
+};
+
+/// Subset of the token bridge's entry points this integration posts to.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum TokenBridgeExecuteMsg {
+    InitiateTransferWithPayload {
+        asset: AssetInfo,
+        amount: Uint128,
+        recipient_chain: u16,
+        recipient: Binary,
+        fee: Uint128,
+        nonce: u32,
+        payload: Binary,
+    },
+}
+
+/// The fields of an inbound `PayloadTransferWithPayload` this contract acts on.
+struct ParsedTransfer {
+    asset_info: AssetInfo,
+    amount: Uint128,
+    fee: Uint128,
+    recipient: Vec<u8>,
+}
+
+impl ParsedTransfer {
+    fn recipient_addr(&self, api: &dyn Api) -> StdResult<Addr> {
+        let human = String::from_utf8(self.recipient.clone())
+            .map_err(|_| StdError::generic_err("recipient is not valid utf-8"))?;
+        api.addr_validate(&human)
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::CompleteTransferWithPayload { data } => {
+            complete_transfer_with_payload(deps, env, info, data)
+        }
+        ExecuteMsg::InitiateTransfer {
+            asset,
+            recipient_chain,
+            recipient,
+            fee,
+            payload,
+        } => initiate_transfer(deps, env, info, asset, recipient_chain, recipient, fee, payload),
+    }
+}
+
+/// Escrow the asset with this contract and post a `PayloadTransferWithPayload`
+/// message to the configured token bridge, rewarding the relayer with `fee`.
+#[allow(clippy::too_many_arguments)]
+fn initiate_transfer(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    asset: Asset,
+    recipient_chain: u16,
+    recipient: Binary,
+    fee: Uint128,
+    payload: Binary,
+) -> StdResult<Response> {
+    let cfg = config_read(deps.storage).load()?;
+
+    if fee > asset.amount {
+        return Err(StdError::generic_err("fee exceeds transfer amount"));
+    }
+
+    // Escrow the funds before they are locked by the token bridge.
+    let escrow_msg = asset.clone().into_msg(&deps.querier, env.contract.address)?;
+
+    // Record the pending outbound transfer so integrators can poll its state.
+    let sequence = transfer_state(deps.storage).may_load()?.map_or(0, |s| s + 1);
+    transfer_state(deps.storage).save(&sequence)?;
+
+    let bridge_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: cfg.token_bridge_contract,
+        msg: to_binary(&TokenBridgeExecuteMsg::InitiateTransferWithPayload {
+            asset: asset.info,
+            amount: asset.amount,
+            recipient_chain,
+            recipient,
+            fee,
+            nonce: sequence as u32,
+            payload,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(escrow_msg)
+        .add_message(bridge_msg)
+        .add_attribute("action", "initiate_transfer")
+        .add_attribute("sequence", sequence.to_string()))
+}
+
+fn complete_transfer_with_payload(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    data: Binary,
+) -> StdResult<Response> {
+    let transfer = parse_transfer_with_payload(&data)?;
+
+    let mut messages = vec![];
+
+    // When the caller is not the designated recipient they are acting as a
+    // relayer and earn the VAA-encoded fee out of the transferred amount.
+    let payout = if info.sender.as_bytes() != transfer.recipient.as_slice() {
+        let relayer_reward = Asset {
+            info: transfer.asset_info.clone(),
+            amount: transfer.fee,
+        };
+        messages.push(relayer_reward.into_msg(&deps.querier, info.sender.clone())?);
+        transfer.fee
+    } else {
+        Uint128::zero()
+    };
+
+    let recipient_amount = transfer.amount.checked_sub(payout)?;
+    let recipient_payout = Asset {
+        info: transfer.asset_info.clone(),
+        amount: recipient_amount,
+    };
+    let recipient_addr = transfer.recipient_addr(deps.api)?;
+    messages.push(recipient_payout.into_msg(&deps.querier, recipient_addr)?);
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attribute("action", "complete_transfer_with_payload")
+        .add_attribute("fee", payout.to_string()))
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::WrappedRegistry { .. } => {
+            Err(StdError::generic_err("unsupported query"))
+        }
+        QueryMsg::TransferState { sequence } => to_binary(&query_transfer_state(deps, sequence)?),
+    }
+}
+
+/// Report whether the outbound transfer with the given sequence has been
+/// emitted by this contract.
+fn query_transfer_state(deps: Deps, sequence: u64) -> StdResult<bool> {
+    let emitted = transfer_state_read(deps.storage)
+        .may_load()?
+        .map_or(false, |latest| sequence <= latest);
+    Ok(emitted)
+}
+
+fn parse_transfer_with_payload(data: &Binary) -> StdResult<ParsedTransfer> {
+    // Token bridge transfer layout (big-endian, amounts are 32-byte uint256):
+    //   payload_id (1) | amount (32) | token_address (32) | token_chain (2)
+    //   | to (32) | to_chain (2) | fee (32) | ... trailing payload
+    let bytes = data.as_slice();
+    if bytes.len() < 1 + 32 + 32 + 2 + 32 + 2 + 32 {
+        return Err(StdError::generic_err("transfer payload too short"));
+    }
+
+    let read_u128 = |slice: &[u8]| -> Uint128 {
+        // The low 16 bytes carry the value; the bridge caps amounts at u128.
+        let mut buf = [0u8; 16];
+        buf.copy_from_slice(&slice[16..32]);
+        Uint128::new(u128::from_be_bytes(buf))
+    };
+
+    let amount = read_u128(&bytes[1..33]);
+    let token_address = &bytes[33..65];
+    let recipient = bytes[67..99].to_vec();
+    let fee = read_u128(&bytes[101..133]);
+
+    Ok(ParsedTransfer {
+        asset_info: AssetInfo::Token {
+            contract_addr: hex::encode(token_address),
+        },
+        amount,
+        fee,
+        recipient,
+    })
+}
@@ -26,6 +26,13 @@ pub enum ExecuteMsg {
     CompleteTransferWithPayload {
         data: Binary,
     },
+    InitiateTransfer {
+        asset: Asset,
+        recipient_chain: u16,
+        recipient: Binary,
+        fee: Uint128,
+        payload: Binary,
+    },
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -36,4 +43,5 @@ pub struct MigrateMsg {}
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
     WrappedRegistry { chain: u16, address: Binary },
+    TransferState { sequence: u64 },
 }
\ No newline at end of file
@@ -0,0 +1,40 @@
+use cosmwasm_std::Storage;
+use cosmwasm_storage::{
+    singleton,
+    singleton_read,
+    ReadonlySingleton,
+    Singleton,
+};
+use schemars::JsonSchema;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+static CONFIG_KEY: &[u8] = b"config";
+static TRANSFER_STATE_KEY: &[u8] = b"transfer_state";
+
+type HumanAddr = String;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigInfo {
+    pub wormhole_contract: HumanAddr,
+    pub token_bridge_contract: HumanAddr,
+}
+
+pub fn config(storage: &mut dyn Storage) -> Singleton<ConfigInfo> {
+    singleton(storage, CONFIG_KEY)
+}
+
+pub fn config_read(storage: &dyn Storage) -> ReadonlySingleton<ConfigInfo> {
+    singleton_read(storage, CONFIG_KEY)
+}
+
+/// Sequence of the latest outbound transfer emitted by this contract.
+pub fn transfer_state(storage: &mut dyn Storage) -> Singleton<u64> {
+    singleton(storage, TRANSFER_STATE_KEY)
+}
+
+pub fn transfer_state_read(storage: &dyn Storage) -> ReadonlySingleton<u64> {
+    singleton_read(storage, TRANSFER_STATE_KEY)
+}